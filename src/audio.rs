@@ -1,8 +1,13 @@
+use anyhow::{anyhow, Context, Result};
 use numpy::ndarray::parallel::prelude::*;
-use numpy::ndarray::{s, Array, Array3, Axis};
+use numpy::ndarray::{s, Array, Array2, Array3, Axis};
 use numpy::{PyArray2, PyArray3, PyReadonlyArray2, PyReadonlyArray3, PyUntypedArrayMethods};
+use pyo3::exceptions::PyRuntimeError;
 use pyo3::prelude::*;
+use serde::Deserialize;
+use std::io::Read;
 use std::ops::Add;
+use std::process::{Command, Stdio};
 
 #[pyfunction]
 pub fn low_frame_rate<'py>(
@@ -107,10 +112,175 @@ pub fn compute_decibel<'py>(
     Ok(PyArray2::from_owned_array(python, decibels))
 }
 
+#[derive(Debug, Deserialize)]
+struct FfprobeAudioStreams {
+    streams: Vec<FfAudioStream>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FfAudioStream {
+    #[serde(default)]
+    sample_rate: Option<String>,
+    #[serde(default)]
+    channels: Option<usize>,
+}
+
+fn ffprobe_audio_meta(input: &str) -> Result<(usize, usize)> {
+    let output = Command::new("ffprobe")
+        .arg("-v")
+        .arg("error")
+        .arg("-select_streams")
+        .arg("a:0")
+        .arg("-show_entries")
+        .arg("stream=sample_rate,channels")
+        .arg("-of")
+        .arg("json")
+        .arg(input)
+        .output()
+        .with_context(|| "failed to execute ffprobe")?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "ffprobe failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    let parsed: FfprobeAudioStreams =
+        serde_json::from_slice(&output.stdout).with_context(|| "failed to parse ffprobe json")?;
+    let stream = parsed
+        .streams
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow!("no audio stream found"))?;
+    let sample_rate = stream
+        .sample_rate
+        .as_ref()
+        .and_then(|s| s.parse::<usize>().ok())
+        .ok_or_else(|| anyhow!("missing sample_rate"))?;
+    let channels = stream.channels.ok_or_else(|| anyhow!("missing channels"))?;
+    Ok((sample_rate, channels))
+}
+
+/// Shell out to ffmpeg to decode+resample, mirroring `ffmpeg_decode_rgb` in `video_core`
+/// but targeting raw interleaved `f32le` PCM instead of rawvideo.
+fn ffmpeg_decode_pcm_f32(input: &str, target_sr: usize, out_channels: usize) -> Result<Vec<f32>> {
+    let mut child = Command::new("ffmpeg")
+        .arg("-v")
+        .arg("error")
+        .arg("-nostdin")
+        .arg("-i")
+        .arg(input)
+        .arg("-map")
+        .arg("0:a:0")
+        .arg("-f")
+        .arg("f32le")
+        .arg("-ar")
+        .arg(target_sr.to_string())
+        .arg("-ac")
+        .arg(out_channels.to_string())
+        .arg("-")
+        .stdout(Stdio::piped())
+        .spawn()
+        .with_context(|| "failed to spawn ffmpeg")?;
+    let mut raw = Vec::new();
+    child
+        .stdout
+        .as_mut()
+        .ok_or_else(|| anyhow!("missing ffmpeg stdout pipe"))?
+        .read_to_end(&mut raw)
+        .with_context(|| "failed to read ffmpeg stdout")?;
+    let status = child.wait().with_context(|| "failed to wait for ffmpeg")?;
+    if !status.success() {
+        return Err(anyhow!("ffmpeg failed to decode audio"));
+    }
+    let remainder = raw.len() % 4;
+    if remainder != 0 {
+        let new_len = raw.len() - remainder;
+        raw.truncate(new_len);
+    }
+    Ok(raw
+        .chunks_exact(4)
+        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect())
+}
+
+/// Mix an interleaved multi-channel PCM buffer down to a single channel by averaging.
+fn mixdown_mono(samples: &[f32], channels: usize) -> Vec<f32> {
+    if channels <= 1 {
+        return samples.to_vec();
+    }
+    samples
+        .chunks_exact(channels)
+        .map(|frame| frame.iter().sum::<f32>() / channels as f32)
+        .collect()
+}
+
+/// Slide a `frame_length`-sample window over `samples` every `hop_length` samples,
+/// zero-padding the final frame so it still has the full `frame_length`.
+fn frame_signal(samples: &[f32], frame_length: usize, hop_length: usize) -> (Vec<f64>, usize) {
+    if frame_length == 0 || hop_length == 0 || samples.is_empty() {
+        return (Vec::new(), 0);
+    }
+    let n_frames = (samples.len() - 1) / hop_length + 1;
+    let mut out = vec![0f64; n_frames * frame_length];
+    for i in 0..n_frames {
+        let start = i * hop_length;
+        for j in 0..frame_length {
+            if let Some(&s) = samples.get(start + j) {
+                out[i * frame_length + j] = s as f64;
+            }
+        }
+    }
+    (out, n_frames)
+}
+
+fn load_audio_impl(
+    input: &str,
+    target_sr: usize,
+    mono: bool,
+    frame_length: usize,
+    hop_length: usize,
+) -> Result<(Vec<f64>, usize, usize, usize)> {
+    let (src_sample_rate, src_channels) = ffprobe_audio_meta(input)?;
+    // `mono` only picks *how* the mixdown happens, not *whether* it happens: the framed output
+    // is always a single channel (see doc comment on `load_audio`). `mono=true` lets ffmpeg's
+    // own downmix do the work during decode; `mono=false` decodes every source channel and
+    // averages them in `mixdown_mono` instead, which is slower but avoids ffmpeg's internal
+    // downmix coefficients when the caller wants a plain per-sample average.
+    let decode_channels = if mono { 1 } else { src_channels.max(1) };
+    let samples = ffmpeg_decode_pcm_f32(input, target_sr, decode_channels)?;
+    let mono_samples = mixdown_mono(&samples, decode_channels);
+    let (frames, n_frames) = frame_signal(&mono_samples, frame_length, hop_length);
+    Ok((frames, n_frames, src_sample_rate, src_channels))
+}
+
+/// Load audio from a path or URL, resample it to `target_sr` and slice it into overlapping
+/// frames, ready to feed straight into [`compute_decibel`]. The returned array is always a
+/// single channel: `mono` does not select the output channel count (there is none to select —
+/// the framed array has no channel axis), only whether ffmpeg's own downmix (`mono=true`) or an
+/// explicit per-sample average over every decoded channel (`mono=false`) produces it.
+#[pyfunction]
+#[pyo3(signature = (path_or_url, target_sr=16000, mono=true, frame_length=400, hop_length=160))]
+pub fn load_audio<'py>(
+    py: Python<'py>,
+    path_or_url: &str,
+    target_sr: usize,
+    mono: bool,
+    frame_length: usize,
+    hop_length: usize,
+) -> PyResult<(Bound<'py, PyArray2<f64>>, usize, usize)> {
+    let (frames, n_frames, sample_rate, channels) = py
+        .allow_threads(|| load_audio_impl(path_or_url, target_sr, mono, frame_length, hop_length))
+        .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+    let array = Array2::from_shape_vec((n_frames, frame_length), frames)
+        .map_err(|e| PyRuntimeError::new_err(format!("failed to build ndarray: {}", e)))?;
+    Ok((PyArray2::from_owned_array(py, array), sample_rate, channels))
+}
+
 pub fn register_module(core_module: &Bound<'_, PyModule>) -> PyResult<()> {
     let audio_module = PyModule::new(core_module.py(), "audio")?;
     audio_module.add_function(wrap_pyfunction!(compute_decibel, &audio_module)?)?;
     audio_module.add_function(wrap_pyfunction!(low_frame_rate, &audio_module)?)?;
+    audio_module.add_function(wrap_pyfunction!(load_audio, &audio_module)?)?;
     core_module.add_submodule(&audio_module)?;
     Ok(())
 }
@@ -2,6 +2,7 @@ mod audio;
 mod text;
 mod text_stream;
 mod video;
+mod word_segment;
 use pyo3::prelude::*;
 
 /// A Python module implemented in Rust.
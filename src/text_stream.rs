@@ -1,15 +1,237 @@
+use crate::word_segment::WordSegmenter;
+use aho_corasick::{AhoCorasick, MatchKind};
 use pyo3::prelude::*;
-use std::collections::HashSet;
+use std::collections::HashMap;
 
-const LEVEL1_ENDINGS: [char; 7] = ['!', '?', '。', '？', '！', '；', ';'];
-const LEVEL2_ENDINGS: [char; 3] = ['、', ',', '，'];
-const LEVEL3_ENDINGS: [char; 2] = [':', '：'];
+const LEVEL1_ENDINGS: [&str; 12] = [
+    "!", "?", "。", "？", "！", "；", ";", "……", "。。。", "...", "?!", "!?",
+];
+const LEVEL2_ENDINGS: [&str; 3] = ["、", ",", "，"];
+const LEVEL3_ENDINGS: [&str; 2] = [":", "："];
+const DEFAULT_PROTECTED_PATTERNS: [&str; 0] = [];
+
+/// Aho-Corasick automaton over every configured level-1/2/3 ending string, with each pattern
+/// tagged by its level so a single scan over the buffer replaces filtering three separate
+/// `HashSet`s per char.
+struct EndingAutomaton {
+    ac: AhoCorasick,
+    levels: Vec<u8>,
+}
+
+impl std::fmt::Debug for EndingAutomaton {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EndingAutomaton")
+            .field("levels", &self.levels)
+            .finish()
+    }
+}
+
+/// Cached [`EndingAutomaton`] plus the ending lists it was built from, so it can be rebuilt only
+/// when `level1/2/3_endings` actually change instead of on every `push`/`flush` call.
+#[derive(Debug, Default)]
+struct CachedAutomaton {
+    built_from: (Vec<String>, Vec<String>, Vec<String>),
+    automaton: Option<std::rc::Rc<EndingAutomaton>>,
+}
+
+impl Clone for CachedAutomaton {
+    fn clone(&self) -> Self {
+        // Only the ending-list snapshot needs to survive a clone; the automaton itself is cheap
+        // to rebuild lazily on next use and `AhoCorasick` doesn't implement `Clone`.
+        Self {
+            built_from: self.built_from.clone(),
+            automaton: None,
+        }
+    }
+}
+
+impl CachedAutomaton {
+    fn get(&mut self, l1: &[String], l2: &[String], l3: &[String]) -> Option<std::rc::Rc<EndingAutomaton>> {
+        let stale = self.automaton.is_none()
+            || self.built_from.0 != l1
+            || self.built_from.1 != l2
+            || self.built_from.2 != l3;
+        if stale {
+            self.automaton = build_ending_automaton(l1, l2, l3).map(std::rc::Rc::new);
+            self.built_from = (l1.to_vec(), l2.to_vec(), l3.to_vec());
+        }
+        self.automaton.clone()
+    }
+}
+
+fn build_ending_automaton(l1: &[String], l2: &[String], l3: &[String]) -> Option<EndingAutomaton> {
+    let mut patterns = Vec::new();
+    let mut levels = Vec::new();
+    for p in l1 {
+        patterns.push(p.as_str());
+        levels.push(1u8);
+    }
+    for p in l2 {
+        patterns.push(p.as_str());
+        levels.push(2u8);
+    }
+    for p in l3 {
+        patterns.push(p.as_str());
+        levels.push(3u8);
+    }
+    if patterns.is_empty() {
+        return None;
+    }
+    let ac = AhoCorasick::builder()
+        .match_kind(MatchKind::LeftmostLongest)
+        .build(&patterns)
+        .ok()?;
+    Some(EndingAutomaton { ac, levels })
+}
+
+/// Map every byte offset in `buffer` to the char position it belongs to, so an Aho-Corasick
+/// match's byte range can be checked against the char-indexed protected/nesting masks.
+fn byte_to_char_positions(buffer: &str) -> Vec<usize> {
+    let mut map = vec![0usize; buffer.len()];
+    for (char_pos, (byte_i, c)) in buffer.char_indices().enumerate() {
+        for b in map.iter_mut().take(byte_i + c.len_utf8()).skip(byte_i) {
+            *b = char_pos;
+        }
+    }
+    map
+}
+
+#[derive(Debug, Default)]
+struct ProtectedTrieNode {
+    children: std::collections::HashMap<char, ProtectedTrieNode>,
+    is_end: bool,
+}
+
+/// Trie of literal protected spans (e.g. a user-added abbreviation like `"Dr."`), built fresh
+/// from `protected_patterns` on each scan since pattern lists stay small. Empty by default:
+/// `……` used to be protected here, but it is now a `LEVEL1_ENDINGS` pattern in its own right, and
+/// protecting a string identical to a configured ending would suppress the ending's own match.
+fn build_protected_trie(patterns: &[String]) -> ProtectedTrieNode {
+    let mut root = ProtectedTrieNode::default();
+    for pattern in patterns {
+        let mut node = &mut root;
+        for c in pattern.chars() {
+            node = node.children.entry(c).or_default();
+        }
+        node.is_end = true;
+    }
+    root
+}
+
+/// Longest protected pattern starting exactly at `chars[start]`, as an inclusive end index.
+fn longest_protected_match(root: &ProtectedTrieNode, chars: &[char], start: usize) -> Option<usize> {
+    let mut node = root;
+    let mut last_end = None;
+    let mut i = start;
+    while i < chars.len() {
+        match node.children.get(&chars[i]) {
+            Some(child) => {
+                node = child;
+                i += 1;
+                if node.is_end {
+                    last_end = Some(i - 1);
+                }
+            }
+            None => break,
+        }
+    }
+    last_end
+}
+
+/// Regex-free `digit+ '.' digit+` rule so decimals like `3.14` don't split on the dot.
+fn match_decimal_span(chars: &[char], start: usize) -> Option<usize> {
+    let n = chars.len();
+    if !chars[start].is_ascii_digit() {
+        return None;
+    }
+    let mut i = start;
+    while i < n && chars[i].is_ascii_digit() {
+        i += 1;
+    }
+    if i >= n || chars[i] != '.' {
+        return None;
+    }
+    i += 1;
+    let frac_start = i;
+    while i < n && chars[i].is_ascii_digit() {
+        i += 1;
+    }
+    if i == frac_start {
+        return None;
+    }
+    Some(i - 1)
+}
+
+/// Regex-free `HH:MM` rule (1-2 digit hour, 2 digit minute) so clock times don't split on the colon.
+fn match_time_span(chars: &[char], start: usize) -> Option<usize> {
+    let n = chars.len();
+    if !chars[start].is_ascii_digit() {
+        return None;
+    }
+    let mut i = start;
+    while i < n && chars[i].is_ascii_digit() && i - start < 2 {
+        i += 1;
+    }
+    if i >= n || chars[i] != ':' {
+        return None;
+    }
+    i += 1;
+    let min_start = i;
+    while i < n && chars[i].is_ascii_digit() && i - min_start < 2 {
+        i += 1;
+    }
+    if i - min_start != 2 {
+        return None;
+    }
+    Some(i - 1)
+}
+
+/// Default paired delimiters (open char -> close char) for nesting-depth tracking.
+///
+/// ASCII `'`/`"` are deliberately excluded: they'd have to be keyed as symmetric toggles (there's
+/// no distinct open/close glyph), and a single unmatched apostrophe -- any ordinary contraction
+/// like "don't" -- would toggle the nesting stack to depth 1 with no second apostrophe in the
+/// text to close it. Since a sentence is only ever cut at depth 0, that one stray apostrophe
+/// would suppress every subsequent sentence ending for the rest of the stream. The CJK paired
+/// brackets below are unambiguous (distinct open/close chars), so they don't have this problem.
+fn default_paired_delimiters() -> HashMap<char, char> {
+    HashMap::from([
+        ('「', '」'),
+        ('『', '』'),
+        ('（', '）'),
+        ('《', '》'),
+        ('(', ')'),
+        ('[', ']'),
+    ])
+}
+
+/// Update a nesting stack for one char: push an opener, pop a matching closer, or toggle a
+/// symmetric delimiter (open == close) whose "open" state is whatever is currently on top.
+fn apply_delimiter(stack: &mut Vec<char>, c: char, pairs: &HashMap<char, char>, closers: &HashMap<char, char>) {
+    if let Some(&close) = pairs.get(&c) {
+        if close == c {
+            if stack.last() == Some(&c) {
+                stack.pop();
+            } else {
+                stack.push(c);
+            }
+        } else {
+            stack.push(c);
+        }
+        return;
+    }
+    if let Some(&open) = closers.get(&c) {
+        if stack.last() == Some(&open) {
+            stack.pop();
+        }
+    }
+}
 
 /// A simple Chinese sentence splitter for text streams.
 ///
 /// This struct is used to split Chinese text into sentences.
 /// It keeps a buffer of text and splits it into sentences when it encounters a sentence ending character.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 #[pyclass]
 pub struct TextStreamSentencizer {
     buffer: String,
@@ -20,11 +242,16 @@ pub struct TextStreamSentencizer {
     #[pyo3(get, set)]
     use_level3_threshold: usize,
     #[pyo3(get, set)]
-    level1_endings: HashSet<char>,
+    level1_endings: Vec<String>,
+    #[pyo3(get, set)]
+    level2_endings: Vec<String>,
     #[pyo3(get, set)]
-    level2_endings: HashSet<char>,
+    level3_endings: Vec<String>,
     #[pyo3(get, set)]
-    level3_endings: HashSet<char>,
+    protected_patterns: Vec<String>,
+    #[pyo3(get, set)]
+    paired_delimiters: HashMap<char, char>,
+    ending_automaton: CachedAutomaton,
 }
 
 #[pymethods]
@@ -32,25 +259,22 @@ impl TextStreamSentencizer {
     #[new]
     #[pyo3(signature = (l1_ends=None, l2_ends=None, l3_ends=None, min_sentence_length=10, use_level2_threshold=50, use_level3_threshold=100))]
     pub fn new(
-        l1_ends: Option<Vec<char>>,
-        l2_ends: Option<Vec<char>>,
-        l3_ends: Option<Vec<char>>,
+        l1_ends: Option<Vec<String>>,
+        l2_ends: Option<Vec<String>>,
+        l3_ends: Option<Vec<String>>,
         min_sentence_length: usize,
         use_level2_threshold: usize,
         use_level3_threshold: usize,
     ) -> Self {
-        let level1_endings = l1_ends
-            .unwrap_or(LEVEL1_ENDINGS.to_vec())
-            .into_iter()
-            .collect();
-        let level2_endings = l2_ends
-            .unwrap_or(LEVEL2_ENDINGS.to_vec())
-            .into_iter()
-            .collect();
-        let level3_endings = l3_ends
-            .unwrap_or(LEVEL3_ENDINGS.to_vec())
-            .into_iter()
-            .collect();
+        let level1_endings = l1_ends.unwrap_or_else(|| {
+            LEVEL1_ENDINGS.iter().map(|s| s.to_string()).collect()
+        });
+        let level2_endings = l2_ends.unwrap_or_else(|| {
+            LEVEL2_ENDINGS.iter().map(|s| s.to_string()).collect()
+        });
+        let level3_endings = l3_ends.unwrap_or_else(|| {
+            LEVEL3_ENDINGS.iter().map(|s| s.to_string()).collect()
+        });
         Self {
             buffer: String::new(),
             min_sentence_length,
@@ -59,9 +283,28 @@ impl TextStreamSentencizer {
             level1_endings,
             level2_endings,
             level3_endings,
+            protected_patterns: DEFAULT_PROTECTED_PATTERNS
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            paired_delimiters: default_paired_delimiters(),
+            ending_automaton: CachedAutomaton::default(),
+        }
+    }
+
+    /// Add a literal protected pattern (e.g. an abbreviation like `"Dr."`) whose internal
+    /// punctuation should never be treated as a sentence boundary.
+    pub fn add_protected_pattern(&mut self, pattern: String) {
+        if !self.protected_patterns.contains(&pattern) {
+            self.protected_patterns.push(pattern);
         }
     }
 
+    /// Remove a previously added protected pattern.
+    pub fn remove_protected_pattern(&mut self, pattern: &str) {
+        self.protected_patterns.retain(|p| p != pattern);
+    }
+
     pub fn push(&mut self, text: &str) -> Vec<String> {
         if text.is_empty() {
             return Vec::new();
@@ -104,7 +347,7 @@ impl TextStreamSentencizer {
         }
     }
 
-    fn split_sentences(&self) -> (Vec<String>, Vec<usize>) {
+    fn split_sentences(&mut self) -> (Vec<String>, Vec<usize>) {
         let end_indices = self.get_sentence_end_indices();
         let mut sentences = Vec::new();
         let mut sent_indices = Vec::new();
@@ -123,43 +366,98 @@ impl TextStreamSentencizer {
         (sentences, sent_indices)
     }
 
-    fn get_sentence_end_indices(&self) -> Vec<usize> {
-        let sents_l1: Vec<usize> = self
-            .buffer
-            .char_indices()
-            .filter_map(|(i, c)| {
-                if self.level1_endings.contains(&c) {
-                    Some(i + c.len_utf8() - 1)
-                } else {
-                    None
+    /// For every char position in the buffer, whether it falls inside a protected span (a
+    /// trie pattern, a decimal number, or an `HH:MM` time) that must not be split on.
+    fn protected_mask(&self, chars: &[char]) -> Vec<bool> {
+        let trie = build_protected_trie(&self.protected_patterns);
+        let n = chars.len();
+        let mut covered = vec![false; n];
+        for start in 0..n {
+            let end = [
+                longest_protected_match(&trie, chars, start),
+                match_decimal_span(chars, start),
+                match_time_span(chars, start),
+            ]
+            .into_iter()
+            .flatten()
+            .max();
+            if let Some(end) = end {
+                if end > start {
+                    for pos in covered.iter_mut().take(end + 1).skip(start) {
+                        *pos = true;
+                    }
                 }
-            })
+            }
+        }
+        covered
+    }
+
+    /// Nesting depth *after* each char position, tracked via [`apply_delimiter`] over
+    /// `paired_delimiters`. Depth persists naturally across `push` calls because `self.buffer`
+    /// always starts at a depth-zero boundary: a sentence is only ever cut when the stack is
+    /// empty, so an unclosed quote simply keeps accumulating in the retained buffer.
+    fn nesting_depth_mask(&self, chars: &[char]) -> Vec<usize> {
+        let closers: HashMap<char, char> = self
+            .paired_delimiters
+            .iter()
+            .filter(|(&open, &close)| open != close)
+            .map(|(&open, &close)| (close, open))
             .collect();
-        let buffer_char_length = self.buffer.chars().count();
+        let mut stack = Vec::new();
+        chars
+            .iter()
+            .map(|&c| {
+                apply_delimiter(&mut stack, c, &self.paired_delimiters, &closers);
+                stack.len()
+            })
+            .collect()
+    }
+
+    /// Single Aho-Corasick pass over the buffer, grouping match end-offsets by level and
+    /// dropping any whose final char falls inside a protected span or unclosed nesting. The
+    /// automaton itself is cached on `self` and only rebuilt when the ending lists change, since
+    /// `push`/`flush` are typically called with small chunks in a tight streaming loop.
+    fn scan_level_matches(&mut self, chars: &[char]) -> [Vec<usize>; 4] {
+        let protected = self.protected_mask(chars);
+        let depth = self.nesting_depth_mask(chars);
+        let byte_to_char = byte_to_char_positions(&self.buffer);
+
+        let mut by_level: [Vec<usize>; 4] = Default::default();
+        let automaton = match self
+            .ending_automaton
+            .get(&self.level1_endings, &self.level2_endings, &self.level3_endings)
+        {
+            Some(a) => a,
+            None => return by_level,
+        };
+
+        for mat in automaton.ac.find_iter(&self.buffer) {
+            let level = automaton.levels[mat.pattern().as_usize()] as usize;
+            let end_byte = mat.end() - 1;
+            let char_pos = byte_to_char[end_byte];
+            let suppressed = protected.get(char_pos).copied().unwrap_or(false)
+                || depth.get(char_pos).copied().unwrap_or(0) > 0;
+            if !suppressed {
+                by_level[level].push(end_byte);
+            }
+        }
+        for level in by_level.iter_mut() {
+            level.sort_unstable();
+        }
+        by_level
+    }
+
+    fn get_sentence_end_indices(&mut self) -> Vec<usize> {
+        let chars: Vec<char> = self.buffer.chars().collect();
+        let mut by_level = self.scan_level_matches(&chars);
+
+        let sents_l1 = std::mem::take(&mut by_level[1]);
+        let buffer_char_length = chars.len();
         if sents_l1.is_empty() && buffer_char_length > self.use_level2_threshold {
-            let sents_l2: Vec<usize> = self
-                .buffer
-                .char_indices()
-                .filter_map(|(i, c)| {
-                    if self.level2_endings.contains(&c) {
-                        Some(i + c.len_utf8() - 1)
-                    } else {
-                        None
-                    }
-                })
-                .collect();
+            let sents_l2 = std::mem::take(&mut by_level[2]);
 
             if sents_l2.is_empty() && buffer_char_length > self.use_level3_threshold {
-                self.buffer
-                    .char_indices()
-                    .filter_map(|(i, c)| {
-                        if self.level3_endings.contains(&c) {
-                            Some(i + c.len_utf8() - 1)
-                        } else {
-                            None
-                        }
-                    })
-                    .collect()
+                std::mem::take(&mut by_level[3])
             } else {
                 sents_l2
             }
@@ -171,11 +469,252 @@ impl TextStreamSentencizer {
     pub fn reset(&mut self) {
         self.buffer.clear();
     }
+
+    /// Wrap a Python async iterator of text chunks as an async iterator of completed sentences,
+    /// preserving this instance's thresholds and level config. Usable as
+    /// `async for sentence in sentencizer.stream(aiter): ...`.
+    pub fn stream(&self, aiter: Py<PyAny>) -> SentenceAsyncIter {
+        let mut fresh = self.clone();
+        fresh.buffer.clear();
+        SentenceAsyncIter {
+            aiter,
+            sentencizer: std::sync::Arc::new(std::sync::Mutex::new(fresh)),
+            pending: std::sync::Arc::new(std::sync::Mutex::new(std::collections::VecDeque::new())),
+            done: std::sync::Arc::new(std::sync::Mutex::new(false)),
+        }
+    }
+}
+
+/// Async iterator bridging a Python `async for` chunk source into [`TextStreamSentencizer`]:
+/// every chunk pulled from `aiter` is pushed into the sentencizer off the GIL, and completed
+/// sentences are yielded one at a time, with the tail flushed once `aiter` is exhausted.
+#[pyclass]
+pub struct SentenceAsyncIter {
+    aiter: Py<PyAny>,
+    sentencizer: std::sync::Arc<std::sync::Mutex<TextStreamSentencizer>>,
+    pending: std::sync::Arc<std::sync::Mutex<std::collections::VecDeque<String>>>,
+    done: std::sync::Arc<std::sync::Mutex<bool>>,
+}
+
+#[pymethods]
+impl SentenceAsyncIter {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let aiter = self.aiter.clone_ref(py);
+        let sentencizer = self.sentencizer.clone();
+        let pending = self.pending.clone();
+        let done = self.done.clone();
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            loop {
+                if let Some(sentence) = pending.lock().unwrap().pop_front() {
+                    return Ok(sentence);
+                }
+                if *done.lock().unwrap() {
+                    return Err(pyo3::exceptions::PyStopAsyncIteration::new_err(()));
+                }
+
+                let next_fut = Python::with_gil(|py| -> PyResult<_> {
+                    let coro = aiter.bind(py).call_method0("__anext__")?;
+                    pyo3_asyncio::tokio::into_future(coro)
+                })?;
+
+                match next_fut.await {
+                    Ok(value) => {
+                        let chunk: String = Python::with_gil(|py| value.extract(py))?;
+                        let sentences = sentencizer.lock().unwrap().push(&chunk);
+                        pending.lock().unwrap().extend(sentences);
+                    }
+                    Err(e) => {
+                        let is_stop = Python::with_gil(|py| {
+                            e.is_instance_of::<pyo3::exceptions::PyStopAsyncIteration>(py)
+                        });
+                        if !is_stop {
+                            return Err(e);
+                        }
+                        *done.lock().unwrap() = true;
+                        let tail = sentencizer.lock().unwrap().flush();
+                        pending.lock().unwrap().extend(tail);
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod ending_scan_tests {
+    use super::*;
+
+    #[test]
+    fn scan_level_matches_groups_matches_by_configured_level() {
+        let mut sentencizer = TextStreamSentencizer::new(None, None, None, 1, 50, 100);
+        let chars: Vec<char> = "hi, there: world!".chars().collect();
+        let by_level = sentencizer.scan_level_matches(&chars);
+        assert_eq!(by_level[1], vec![chars.len() - 1]); // trailing "!"
+        assert_eq!(by_level[2], vec![2]); // ","
+        assert_eq!(by_level[3], vec![9]); // ":"
+    }
+
+    #[test]
+    fn scan_level_matches_suppresses_a_match_inside_unclosed_nesting() {
+        let mut sentencizer = TextStreamSentencizer::new(None, None, None, 1, 50, 100);
+        let chars: Vec<char> = "「hi!".chars().collect();
+        let by_level = sentencizer.scan_level_matches(&chars);
+        assert!(by_level[1].is_empty());
+    }
+
+    #[test]
+    fn cached_automaton_is_reused_across_calls_with_unchanged_endings() {
+        let mut cache = CachedAutomaton::default();
+        let l1 = vec!["!".to_string()];
+        let l2: Vec<String> = Vec::new();
+        let l3: Vec<String> = Vec::new();
+        let first = cache.get(&l1, &l2, &l3).expect("automaton built");
+        let second = cache.get(&l1, &l2, &l3).expect("automaton reused");
+        assert!(std::rc::Rc::ptr_eq(&first, &second));
+    }
+
+    #[test]
+    fn cached_automaton_rebuilds_when_the_ending_lists_change() {
+        let mut cache = CachedAutomaton::default();
+        let l1 = vec!["!".to_string()];
+        let l2: Vec<String> = Vec::new();
+        let l3: Vec<String> = Vec::new();
+        let first = cache.get(&l1, &l2, &l3).expect("automaton built");
+
+        let l1_changed = vec!["!".to_string(), "?".to_string()];
+        let second = cache
+            .get(&l1_changed, &l2, &l3)
+            .expect("automaton rebuilt");
+        assert!(!std::rc::Rc::ptr_eq(&first, &second));
+    }
+
+    #[test]
+    fn cached_automaton_returns_none_when_no_endings_are_configured() {
+        let mut cache = CachedAutomaton::default();
+        let empty: Vec<String> = Vec::new();
+        assert!(cache.get(&empty, &empty, &empty).is_none());
+    }
+}
+
+#[cfg(test)]
+mod nesting_depth_tests {
+    use super::*;
+
+    #[test]
+    fn default_delimiters_do_not_include_ascii_quotes() {
+        let pairs = default_paired_delimiters();
+        assert!(!pairs.contains_key(&'\''));
+        assert!(!pairs.contains_key(&'"'));
+    }
+
+    #[test]
+    fn cjk_paired_brackets_open_and_close_back_to_depth_zero() {
+        let mut sentencizer = TextStreamSentencizer::new(None, None, None, 1, 50, 100);
+        let chars: Vec<char> = "「你好」".chars().collect();
+        let depth = sentencizer.nesting_depth_mask(&chars);
+        assert_eq!(depth, vec![1, 1, 1, 0]);
+    }
+
+    #[test]
+    fn an_ordinary_apostrophe_contraction_never_raises_the_nesting_depth() {
+        // Regression test for the bug where ASCII '\'' was tracked as a symmetric toggle: a bare
+        // contraction like "don't" would push the nesting stack to depth 1 with no second
+        // apostrophe in the text to close it, permanently suppressing every later sentence end.
+        let mut sentencizer = TextStreamSentencizer::new(None, None, None, 1, 50, 100);
+        let chars: Vec<char> = "I don't know. Really.".chars().collect();
+        let depth = sentencizer.nesting_depth_mask(&chars);
+        assert!(depth.iter().all(|&d| d == 0));
+    }
+
+    #[test]
+    fn an_unclosed_cjk_opener_keeps_depth_above_zero_for_the_rest_of_the_buffer() {
+        let mut sentencizer = TextStreamSentencizer::new(None, None, None, 1, 50, 100);
+        let chars: Vec<char> = "「still open. more text".chars().collect();
+        let depth = sentencizer.nesting_depth_mask(&chars);
+        assert!(depth[1..].iter().all(|&d| d > 0));
+    }
+
+    #[test]
+    fn apostrophe_sentences_still_get_split_end_to_end() {
+        let mut sentencizer = TextStreamSentencizer::new(None, None, None, 1, 50, 100);
+        let sentences = sentencizer.push("I don't know! Really, I don't?");
+        assert_eq!(sentences, vec!["I don't know!".to_string()]);
+    }
+}
+
+#[cfg(test)]
+mod protected_span_tests {
+    use super::*;
+
+    #[test]
+    fn longest_protected_match_picks_the_longest_pattern_starting_at_start() {
+        let trie = build_protected_trie(&["Dr".to_string(), "Dr.".to_string()]);
+        let chars: Vec<char> = "Dr. Smith".chars().collect();
+        // Both "Dr" (end index 1) and "Dr." (end index 2) match at position 0; the trie should
+        // return the longer one.
+        assert_eq!(longest_protected_match(&trie, &chars, 0), Some(2));
+    }
+
+    #[test]
+    fn longest_protected_match_returns_none_when_nothing_matches() {
+        let trie = build_protected_trie(&["Dr.".to_string()]);
+        let chars: Vec<char> = "Mr. Smith".chars().collect();
+        assert_eq!(longest_protected_match(&trie, &chars, 0), None);
+    }
+
+    #[test]
+    fn match_decimal_span_covers_digits_dot_digits() {
+        let chars: Vec<char> = "3.14 more".chars().collect();
+        assert_eq!(match_decimal_span(&chars, 0), Some(3));
+    }
+
+    #[test]
+    fn match_decimal_span_rejects_a_trailing_dot_with_no_fraction() {
+        let chars: Vec<char> = "3. more".chars().collect();
+        assert_eq!(match_decimal_span(&chars, 0), None);
+    }
+
+    #[test]
+    fn match_decimal_span_rejects_a_non_digit_start() {
+        let chars: Vec<char> = "a.1".chars().collect();
+        assert_eq!(match_decimal_span(&chars, 0), None);
+    }
+
+    #[test]
+    fn match_time_span_covers_hh_mm() {
+        let chars: Vec<char> = "9:30am".chars().collect();
+        assert_eq!(match_time_span(&chars, 0), Some(3));
+    }
+
+    #[test]
+    fn match_time_span_requires_exactly_two_minute_digits() {
+        let chars: Vec<char> = "9:3am".chars().collect();
+        assert_eq!(match_time_span(&chars, 0), None);
+    }
+
+    #[test]
+    fn protected_mask_covers_decimal_and_time_spans_but_not_surrounding_text() {
+        let mut sentencizer = TextStreamSentencizer::new(None, None, None, 1, 50, 100);
+        sentencizer.protected_patterns.clear();
+        let chars: Vec<char> = "x 3.14 y 9:30 z".chars().collect();
+        let mask = sentencizer.protected_mask(&chars);
+        assert!(!mask[0]); // 'x'
+        assert!(mask[2] && mask[3] && mask[4] && mask[5]); // "3.14"
+        assert!(mask[9] && mask[10] && mask[11] && mask[12]); // "9:30"
+        assert!(!mask[mask.len() - 1]); // 'z'
+    }
 }
 
 pub fn register_module(core_module: &Bound<'_, PyModule>) -> PyResult<()> {
     let audio_module = PyModule::new(core_module.py(), "text_stream")?;
     audio_module.add_class::<TextStreamSentencizer>()?;
+    audio_module.add_class::<WordSegmenter>()?;
+    audio_module.add_class::<SentenceAsyncIter>()?;
     core_module.add_submodule(&audio_module)?;
     Ok(())
 }
@@ -1,7 +1,8 @@
 use anyhow::{anyhow, Context, Result};
-use numpy::PyArray4;
+use numpy::{PyArray3, PyArray4};
 use pyo3::exceptions::PyRuntimeError;
 use pyo3::prelude::*;
+use pyo3::types::PyTuple;
 use serde::Deserialize;
 use std::process::Command;
 use video_rs::decode::Decoder;
@@ -125,18 +126,96 @@ fn ffprobe_meta(input: &str) -> Result<VideoMeta> {
     Ok(VideoMeta { width, height, fps, duration })
 }
 
-fn videors_decode_rgb(input: &str, expected_width: usize, expected_height: usize) -> Result<(Vec<u8>, usize)> {
-    video_rs::init().map_err(|e| anyhow!(format!("video-rs init failed: {e:?}")))?;
-    let url = if input.starts_with("http://") || input.starts_with("https://") || input.starts_with("rtsp://") {
-        input.parse::<Url>().map_err(|e| anyhow!(format!("invalid url: {e}")))?
+fn parse_input_url(input: &str) -> Result<Url> {
+    if input.starts_with("http://") || input.starts_with("https://") || input.starts_with("rtsp://") {
+        input.parse::<Url>().map_err(|e| anyhow!(format!("invalid url: {e}")))
     } else {
-        Url::from_file_path(input).map_err(|_| anyhow!("invalid file path"))?
-    };
+        Url::from_file_path(input).map_err(|_| anyhow!("invalid file path"))
+    }
+}
+
+fn open_decoder(input: &str) -> Result<Decoder> {
+    video_rs::init().map_err(|e| anyhow!(format!("video-rs init failed: {e:?}")))?;
+    let url = parse_input_url(input)?;
+    Decoder::new(url).map_err(|e| anyhow!(format!("decoder new failed: {e:?}")))
+}
+
+/// Per-frame pixel data accumulated while decoding, already converted to the requested
+/// `pix_fmt`. `video_rs::Decoder` itself always emits RGB24 (the `c != 3` checks below are
+/// load-bearing, not defensive) -- there is no decode-time knob to make the scaler emit gray or
+/// yuv420p directly. The closest this crate can get to "emits the requested format directly" is
+/// converting each frame the moment it comes off the decoder, so a gray/yuv420p load never holds
+/// the full-clip RGB24 buffer in memory just to throw it away, the way a decode-then-convert
+/// pass over the whole `Vec<u8>` would.
+enum FrameAccum {
+    Rgb24(Vec<u8>),
+    Gray(Vec<u8>),
+    Yuv420p { y: Vec<u8>, u: Vec<u8>, v: Vec<u8> },
+}
+
+impl FrameAccum {
+    fn new(fmt: PixFmt) -> Self {
+        match fmt {
+            PixFmt::Rgb24 => FrameAccum::Rgb24(Vec::new()),
+            PixFmt::Gray => FrameAccum::Gray(Vec::new()),
+            PixFmt::Yuv420p => FrameAccum::Yuv420p { y: Vec::new(), u: Vec::new(), v: Vec::new() },
+        }
+    }
+
+    /// Convert one decoded RGB24 frame into this accumulator's format and append it.
+    fn push_rgb_frame(&mut self, rgb: &[u8], width: usize, height: usize) {
+        match self {
+            FrameAccum::Rgb24(buf) => buf.extend_from_slice(rgb),
+            FrameAccum::Gray(buf) => buf.extend(rgb_to_gray(rgb)),
+            FrameAccum::Yuv420p { y, u, v } => {
+                let (yy, uu, vv) = rgb_to_yuv420p(rgb, width, height);
+                y.extend(yy);
+                u.extend(uu);
+                v.extend(vv);
+            }
+        }
+    }
+
+    /// Merge another segment's accumulator into this one, in timestamp order. Both must have
+    /// been built for the same `pix_fmt` since every segment in a `load_impl` call shares it.
+    fn extend(&mut self, other: FrameAccum) {
+        match (self, other) {
+            (FrameAccum::Rgb24(a), FrameAccum::Rgb24(b)) => a.extend(b),
+            (FrameAccum::Gray(a), FrameAccum::Gray(b)) => a.extend(b),
+            (FrameAccum::Yuv420p { y: ay, u: au, v: av }, FrameAccum::Yuv420p { y: by, u: bu, v: bv }) => {
+                ay.extend(by);
+                au.extend(bu);
+                av.extend(bv);
+            }
+            _ => unreachable!("segment accumulators always share the requested pix_fmt"),
+        }
+    }
 
-    let mut decoder = Decoder::new(url).map_err(|e| anyhow!(format!("decoder new failed: {e:?}")))?;
+    /// Frame count implied by the primary plane's length (`y` for yuv420p, the whole frame
+    /// otherwise), used to validate the decode produced whole frames in the requested format.
+    fn frame_count(&self, width: usize, height: usize) -> Option<usize> {
+        let (len, per_frame) = match self {
+            FrameAccum::Rgb24(b) => (b.len(), width * height * 3),
+            FrameAccum::Gray(b) => (b.len(), width * height),
+            FrameAccum::Yuv420p { y, .. } => (y.len(), width * height),
+        };
+        if per_frame == 0 || len % per_frame != 0 {
+            return None;
+        }
+        Some(len / per_frame)
+    }
+}
+
+fn videors_decode_rgb(
+    input: &str,
+    expected_width: usize,
+    expected_height: usize,
+    fmt: PixFmt,
+) -> Result<(FrameAccum, usize)> {
+    let mut decoder = open_decoder(input)?;
     let mut width: usize = 0;
     let mut height: usize = 0;
-    let mut bytes: Vec<u8> = Vec::new();
+    let mut accum = FrameAccum::new(fmt);
     let mut frames: usize = 0;
 
     for res in decoder.decode_iter() {
@@ -159,16 +238,17 @@ fn videors_decode_rgb(input: &str, expected_width: usize, expected_height: usize
             }
         }
         if let Some(slice) = frame.as_slice() {
-            bytes.extend_from_slice(slice);
+            accum.push_rgb_frame(slice, width, height);
         } else {
             let owned = frame.to_owned();
-            bytes.extend_from_slice(owned.as_slice().ok_or_else(|| anyhow!("failed to get owned slice"))?);
+            let slice = owned.as_slice().ok_or_else(|| anyhow!("failed to get owned slice"))?;
+            accum.push_rgb_frame(slice, width, height);
         }
         frames += 1;
     }
 
     if width == 0 || height == 0 { return Err(anyhow!("no frames decoded")); }
-    Ok((bytes, frames))
+    Ok((accum, frames))
 }
 
 // ffmpeg CLI path removed in favor of video-rs
@@ -221,48 +301,502 @@ fn ffprobe_keyframes(input: &str, fps: f64) -> Result<Vec<(usize, f64, String, u
     Ok(out)
 }
 
+fn resolve_num_threads(num_threads: usize) -> usize {
+    if num_threads > 0 {
+        return num_threads;
+    }
+    std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+}
+
+/// Partition `[0, duration)` into `num_threads` contiguous segments, snapping each interior
+/// boundary back to the nearest preceding keyframe so every segment can seek cleanly.
+fn keyframe_aligned_segments(keyframe_times: &[f64], duration: f64, num_threads: usize) -> Vec<(f64, f64)> {
+    if num_threads <= 1 || keyframe_times.len() < 2 || duration <= 0.0 {
+        return vec![(0.0, duration)];
+    }
+    let mut boundaries = vec![0.0];
+    for i in 1..num_threads {
+        let target = duration * (i as f64) / (num_threads as f64);
+        let seek_time = keyframe_times
+            .iter()
+            .rev()
+            .find(|&&t| t <= target)
+            .copied()
+            .unwrap_or(0.0);
+        boundaries.push(seek_time);
+    }
+    boundaries.push(duration);
+    boundaries.dedup_by(|a, b| (*a - *b).abs() < f64::EPSILON);
+    boundaries.windows(2).map(|w| (w[0], w[1])).collect()
+}
+
+fn decode_segment_rgb(
+    input: &str,
+    width: usize,
+    height: usize,
+    start: f64,
+    end: f64,
+    fmt: PixFmt,
+) -> Result<FrameAccum> {
+    let mut decoder = open_decoder(input)?;
+    if start > 0.0 {
+        decoder
+            .seek(start as f32)
+            .map_err(|e| anyhow!(format!("seek failed: {e:?}")))?;
+    }
+    let mut accum = FrameAccum::new(fmt);
+    for res in decoder.decode_iter() {
+        let (ts, frame) = res.map_err(|e| anyhow!(format!("decode error: {e:?}")))?;
+        if ts.as_secs_f64() >= end {
+            break;
+        }
+        let rgb = frame_to_bytes(&frame, width, height)?;
+        accum.push_rgb_frame(&rgb, width, height);
+    }
+    Ok(accum)
+}
+
+/// Decode `input` using one `video_rs::Decoder` per keyframe-aligned segment, run concurrently
+/// on plain OS threads, then reassemble the segment buffers in timestamp order.
+fn videors_decode_rgb_parallel(
+    input: &str,
+    width: usize,
+    height: usize,
+    duration: f64,
+    keyframe_times: &[f64],
+    num_threads: usize,
+    fmt: PixFmt,
+) -> Result<(FrameAccum, usize)> {
+    let segments = keyframe_aligned_segments(keyframe_times, duration, num_threads);
+    let handles: Vec<_> = segments
+        .into_iter()
+        .map(|(start, end)| {
+            let input = input.to_string();
+            std::thread::spawn(move || decode_segment_rgb(&input, width, height, start, end, fmt))
+        })
+        .collect();
+
+    let mut accum = FrameAccum::new(fmt);
+    for handle in handles {
+        let segment_accum = handle.join().map_err(|_| anyhow!("decode thread panicked"))??;
+        accum.extend(segment_accum);
+    }
+    let frames = accum
+        .frame_count(width, height)
+        .ok_or_else(|| anyhow!("invalid frame size"))?;
+    Ok((accum, frames))
+}
+
+/// Output pixel format requested for `load_from_path`/`load_from_url`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PixFmt {
+    Rgb24,
+    Gray,
+    Yuv420p,
+}
+
+impl PixFmt {
+    fn parse(s: &str) -> Result<Self> {
+        match s {
+            "rgb24" => Ok(PixFmt::Rgb24),
+            "gray" => Ok(PixFmt::Gray),
+            "yuv420p" => Ok(PixFmt::Yuv420p),
+            other => Err(anyhow!(format!("unsupported pix_fmt: {other}"))),
+        }
+    }
+}
+
+fn rgb_to_gray(rgb: &[u8]) -> Vec<u8> {
+    rgb.chunks_exact(3)
+        .map(|px| {
+            (0.299 * px[0] as f64 + 0.587 * px[1] as f64 + 0.114 * px[2] as f64).round() as u8
+        })
+        .collect()
+}
+
+/// Convert one RGB24 frame into BT.601 Y/U/V planes, with U/V averaged over 2x2 blocks to
+/// produce the half-resolution chroma planes `yuv420p` expects.
+fn rgb_to_yuv420p(rgb: &[u8], width: usize, height: usize) -> (Vec<u8>, Vec<u8>, Vec<u8>) {
+    let cw = width.div_ceil(2);
+    let ch = height.div_ceil(2);
+    let mut y_plane = vec![0u8; width * height];
+    let mut u_sum = vec![0i32; cw * ch];
+    let mut v_sum = vec![0i32; cw * ch];
+    let mut counts = vec![0i32; cw * ch];
+    for row in 0..height {
+        for col in 0..width {
+            let idx = (row * width + col) * 3;
+            let r = rgb[idx] as f64;
+            let g = rgb[idx + 1] as f64;
+            let b = rgb[idx + 2] as f64;
+            let y = 0.299 * r + 0.587 * g + 0.114 * b;
+            y_plane[row * width + col] = y.round().clamp(0.0, 255.0) as u8;
+            let u = -0.14713 * r - 0.28886 * g + 0.436 * b + 128.0;
+            let v = 0.615 * r - 0.51499 * g - 0.10001 * b + 128.0;
+            let cidx = (row / 2) * cw + (col / 2);
+            u_sum[cidx] += u.round() as i32;
+            v_sum[cidx] += v.round() as i32;
+            counts[cidx] += 1;
+        }
+    }
+    let plane = |sum: Vec<i32>| -> Vec<u8> {
+        sum.iter()
+            .zip(counts.iter())
+            .map(|(s, c)| (s / (*c).max(1)).clamp(0, 255) as u8)
+            .collect()
+    };
+    (y_plane, plane(u_sum), plane(v_sum))
+}
+
 fn load_impl<'py>(
     py: Python<'py>,
     input: &str,
-) -> PyResult<(Bound<'py, PyArray4<u8>>, f64, f64, usize, usize, usize)> {
+    num_threads: usize,
+    pix_fmt: &str,
+) -> PyResult<Py<PyAny>> {
     let meta = ffprobe_meta(input).map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
-    let (bytes, frames) = videors_decode_rgb(input, meta.width, meta.height)
-        .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
-    let frame_size = meta.width * meta.height * 3;
-    if frame_size == 0 {
-        return Err(PyRuntimeError::new_err("invalid frame size"));
-    }
-    if bytes.len() != frames * frame_size {
+    let fmt = PixFmt::parse(pix_fmt).map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+    let num_threads = resolve_num_threads(num_threads);
+    let (accum, frames) = py.allow_threads(|| -> Result<(FrameAccum, usize)> {
+        if num_threads > 1 {
+            let keyframe_times: Vec<f64> = ffprobe_keyframes(input, meta.fps)
+                .ok()
+                .map(|kfs| kfs.iter().map(|(_, t, _, _)| *t).collect())
+                .unwrap_or_default();
+            if keyframe_times.len() >= 2 {
+                return videors_decode_rgb_parallel(
+                    input,
+                    meta.width,
+                    meta.height,
+                    meta.duration,
+                    &keyframe_times,
+                    num_threads,
+                    fmt,
+                );
+            }
+            // Seeking isn't reliable (e.g. a stream lacking a seekable index): fall back to
+            // the single-threaded sequential path.
+        }
+        videors_decode_rgb(input, meta.width, meta.height, fmt)
+    })
+    .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+    if accum.frame_count(meta.width, meta.height) != Some(frames) {
         return Err(PyRuntimeError::new_err("incomplete frame buffer"));
     }
-    let array =
-        ndarray::Array4::from_shape_vec((frames, meta.height, meta.width, 3usize), bytes)
-            .map_err(|e| PyRuntimeError::new_err(format!("failed to build ndarray: {}", e)))?;
-    let py_arr = PyArray4::from_owned_array(py, array);
-    Ok((
-        py_arr,
-        meta.fps,
-        meta.duration,
-        meta.width,
-        meta.height,
-        frames,
-    ))
+
+    let (main, u, v): (Py<PyAny>, Option<Py<PyAny>>, Option<Py<PyAny>>) = match accum {
+        FrameAccum::Rgb24(bytes) => {
+            let array =
+                ndarray::Array4::from_shape_vec((frames, meta.height, meta.width, 3usize), bytes)
+                    .map_err(|e| PyRuntimeError::new_err(format!("failed to build ndarray: {}", e)))?;
+            (PyArray4::from_owned_array(py, array).into_any().unbind(), None, None)
+        }
+        FrameAccum::Gray(gray) => {
+            let array = ndarray::Array3::from_shape_vec((frames, meta.height, meta.width), gray)
+                .map_err(|e| PyRuntimeError::new_err(format!("failed to build ndarray: {}", e)))?;
+            (PyArray3::from_owned_array(py, array).into_any().unbind(), None, None)
+        }
+        FrameAccum::Yuv420p { y: y_bytes, u: u_bytes, v: v_bytes } => {
+            let cw = meta.width.div_ceil(2);
+            let ch = meta.height.div_ceil(2);
+            let y_array = ndarray::Array3::from_shape_vec((frames, meta.height, meta.width), y_bytes)
+                .map_err(|e| PyRuntimeError::new_err(format!("failed to build ndarray: {}", e)))?;
+            let u_array = ndarray::Array3::from_shape_vec((frames, ch, cw), u_bytes)
+                .map_err(|e| PyRuntimeError::new_err(format!("failed to build ndarray: {}", e)))?;
+            let v_array = ndarray::Array3::from_shape_vec((frames, ch, cw), v_bytes)
+                .map_err(|e| PyRuntimeError::new_err(format!("failed to build ndarray: {}", e)))?;
+            (
+                PyArray3::from_owned_array(py, y_array).into_any().unbind(),
+                Some(PyArray3::from_owned_array(py, u_array).into_any().unbind()),
+                Some(PyArray3::from_owned_array(py, v_array).into_any().unbind()),
+            )
+        }
+    };
+
+    // rgb24/gray keep the pre-pix_fmt 6-tuple shape (array, fps, duration, width, height,
+    // frames) so callers written before this pix_fmt argument existed keep unpacking correctly.
+    // yuv420p needs its extra u/v planes and so gets an 8-tuple, but that shape is opt-in: it
+    // only appears for a pix_fmt no caller could have depended on before this commit.
+    let tuple = match (u, v) {
+        (Some(u), Some(v)) => PyTuple::new(
+            py,
+            [
+                main,
+                u,
+                v,
+                meta.fps.into_pyobject(py)?.into_any().unbind(),
+                meta.duration.into_pyobject(py)?.into_any().unbind(),
+                meta.width.into_pyobject(py)?.into_any().unbind(),
+                meta.height.into_pyobject(py)?.into_any().unbind(),
+                frames.into_pyobject(py)?.into_any().unbind(),
+            ],
+        )?,
+        _ => PyTuple::new(
+            py,
+            [
+                main,
+                meta.fps.into_pyobject(py)?.into_any().unbind(),
+                meta.duration.into_pyobject(py)?.into_any().unbind(),
+                meta.width.into_pyobject(py)?.into_any().unbind(),
+                meta.height.into_pyobject(py)?.into_any().unbind(),
+                frames.into_pyobject(py)?.into_any().unbind(),
+            ],
+        )?,
+    };
+    Ok(tuple.into_any().unbind())
 }
 
+/// Load a video as an ndarray in the requested `pix_fmt`.
+///
+/// Returns `(array, fps, duration, width, height, n_frames)` for `"rgb24"`/`"gray"`, matching
+/// the shape this function returned before `pix_fmt` was added. `"yuv420p"` instead returns
+/// `(y, u, v, fps, duration, width, height, n_frames)`, with `u`/`v` at half resolution.
 #[pyfunction]
+#[pyo3(signature = (path, num_threads=0, pix_fmt="rgb24"))]
 pub fn load_from_path<'py>(
     py: Python<'py>,
     path: &str,
-) -> PyResult<(Bound<'py, PyArray4<u8>>, f64, f64, usize, usize, usize)> {
-    load_impl(py, path)
+    num_threads: usize,
+    pix_fmt: &str,
+) -> PyResult<Py<PyAny>> {
+    load_impl(py, path, num_threads, pix_fmt)
 }
 
+/// See [`load_from_path`]; identical behavior against a URL input.
 #[pyfunction]
+#[pyo3(signature = (url, num_threads=0, pix_fmt="rgb24"))]
 pub fn load_from_url<'py>(
     py: Python<'py>,
     url: &str,
-) -> PyResult<(Bound<'py, PyArray4<u8>>, f64, f64, usize, usize, usize)> {
-    load_impl(py, url)
+    num_threads: usize,
+    pix_fmt: &str,
+) -> PyResult<Py<PyAny>> {
+    load_impl(py, url, num_threads, pix_fmt)
+}
+
+const SCENE_GRID: usize = 32;
+
+/// Downsample an RGB24 frame to a fixed `SCENE_GRID x SCENE_GRID` luma grid via block averaging.
+fn downsample_grid_luma(bytes: &[u8], width: usize, height: usize) -> Vec<f64> {
+    let mut grid = vec![0f64; SCENE_GRID * SCENE_GRID];
+    let mut counts = vec![0u32; SCENE_GRID * SCENE_GRID];
+    for y in 0..height {
+        let gy = (y * SCENE_GRID) / height.max(1);
+        for x in 0..width {
+            let gx = (x * SCENE_GRID) / width.max(1);
+            let idx = (y * width + x) * 3;
+            let r = bytes[idx] as f64;
+            let g = bytes[idx + 1] as f64;
+            let b = bytes[idx + 2] as f64;
+            let luma = 0.299 * r + 0.587 * g + 0.114 * b;
+            let cell = gy * SCENE_GRID + gx;
+            grid[cell] += luma;
+            counts[cell] += 1;
+        }
+    }
+    for (cell, count) in grid.iter_mut().zip(counts.iter()) {
+        if *count > 0 {
+            *cell /= *count as f64;
+        }
+    }
+    grid
+}
+
+/// Mean absolute luma difference between two downsampled grids, normalized to `[0, 1]`.
+fn grid_luma_diff(a: &[f64], b: &[f64]) -> f64 {
+    let sum: f64 = a.iter().zip(b.iter()).map(|(x, y)| (x - y).abs()).sum();
+    (sum / a.len() as f64) / 255.0
+}
+
+fn detect_scenes_impl(
+    input: &str,
+    threshold: f64,
+    min_scene_len: usize,
+    max_scene_len: usize,
+) -> Result<Vec<(usize, usize, f64, f64)>> {
+    let meta = ffprobe_meta(input)?;
+    let mut decoder = open_decoder(input)?;
+
+    let mut scenes: Vec<(usize, usize, f64, f64)> = Vec::new();
+    let mut prev_grid: Option<Vec<f64>> = None;
+    let mut scene_start = 0usize;
+    let mut frames_since_cut = 0usize;
+    let mut frame_index = 0usize;
+
+    for res in decoder.decode_iter() {
+        let (_ts, frame) = res.map_err(|e| anyhow!(format!("decode error: {e:?}")))?;
+        let bytes = frame_to_bytes(&frame, meta.width, meta.height)?;
+        let grid = downsample_grid_luma(&bytes, meta.width, meta.height);
+
+        if let Some(prev) = &prev_grid {
+            let score = grid_luma_diff(prev, &grid);
+            let forced = frames_since_cut >= max_scene_len;
+            if (score > threshold && frames_since_cut >= min_scene_len) || forced {
+                let scene_end = frame_index - 1;
+                scenes.push((
+                    scene_start,
+                    scene_end,
+                    scene_start as f64 / meta.fps,
+                    frame_index as f64 / meta.fps,
+                ));
+                scene_start = frame_index;
+                frames_since_cut = 0;
+            }
+        }
+
+        prev_grid = Some(grid);
+        frames_since_cut += 1;
+        frame_index += 1;
+    }
+
+    if frame_index > 0 {
+        let scene_end = frame_index - 1;
+        scenes.push((
+            scene_start,
+            scene_end,
+            scene_start as f64 / meta.fps,
+            frame_index as f64 / meta.fps,
+        ));
+    }
+
+    Ok(scenes)
+}
+
+/// Detect semantic scene cuts by comparing downsampled luma grids between consecutive frames,
+/// streaming the decode so only the previous frame's grid is kept in memory.
+#[pyfunction]
+#[pyo3(signature = (path, threshold=0.3, min_scene_len=10, max_scene_len=9999))]
+pub fn detect_scenes(
+    py: Python<'_>,
+    path: &str,
+    threshold: f64,
+    min_scene_len: usize,
+    max_scene_len: usize,
+) -> PyResult<Vec<(usize, usize, f64, f64)>> {
+    py.allow_threads(|| detect_scenes_impl(path, threshold, min_scene_len, max_scene_len))
+        .map_err(|e| PyRuntimeError::new_err(e.to_string()))
+}
+
+fn frame_to_bytes(
+    frame: &video_rs::ndarray::Array3<u8>,
+    width: usize,
+    height: usize,
+) -> Result<Vec<u8>> {
+    let shape = frame.shape();
+    if shape.len() != 3 {
+        return Err(anyhow!("unexpected frame dims"));
+    }
+    let (h, w, c) = (shape[0], shape[1], shape[2]);
+    if c != 3 {
+        return Err(anyhow!("expected RGB channels=3"));
+    }
+    if w != width || h != height {
+        return Err(anyhow!("variable frame size not supported"));
+    }
+    match frame.as_slice() {
+        Some(slice) => Ok(slice.to_vec()),
+        None => {
+            let owned = frame.to_owned();
+            Ok(owned
+                .as_slice()
+                .ok_or_else(|| anyhow!("failed to get owned slice"))?
+                .to_vec())
+        }
+    }
+}
+
+/// Iterator-style reader that decodes a video in GIL-releasing batches instead of
+/// materializing the whole clip in memory at once.
+///
+/// Returned by [`open_from_path`]/[`open_from_url`]; call `next()` (or iterate over it
+/// from Python) to pull successive `(batch, H, W, 3)` arrays until the clip is exhausted.
+#[pyclass]
+pub struct VideoFrameReader {
+    decoder: Decoder,
+    #[pyo3(get)]
+    width: usize,
+    #[pyo3(get)]
+    height: usize,
+    #[pyo3(get)]
+    fps: f64,
+    #[pyo3(get)]
+    duration: f64,
+    batch_size: usize,
+    finished: bool,
+}
+
+#[pymethods]
+impl VideoFrameReader {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        py: Python<'py>,
+    ) -> PyResult<Option<Bound<'py, PyArray4<u8>>>> {
+        if slf.finished {
+            return Ok(None);
+        }
+        let width = slf.width;
+        let height = slf.height;
+        let batch_size = slf.batch_size;
+        let reader = &mut *slf;
+        let outcome: Result<(Vec<u8>, usize, bool)> = py.allow_threads(|| {
+            let mut bytes = Vec::with_capacity(width * height * 3 * batch_size);
+            let mut count = 0usize;
+            let mut reached_end = true;
+            for res in reader.decoder.decode_iter() {
+                let (_ts, frame) = res.map_err(|e| anyhow!(format!("decode error: {e:?}")))?;
+                bytes.extend_from_slice(&frame_to_bytes(&frame, width, height)?);
+                count += 1;
+                if count == batch_size {
+                    reached_end = false;
+                    break;
+                }
+            }
+            Ok((bytes, count, reached_end))
+        });
+        let (bytes, count, reached_end) = outcome.map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+        if count == 0 {
+            slf.finished = true;
+            return Ok(None);
+        }
+        if reached_end {
+            slf.finished = true;
+        }
+        let array = ndarray::Array4::from_shape_vec((count, height, width, 3usize), bytes)
+            .map_err(|e| PyRuntimeError::new_err(format!("failed to build ndarray: {}", e)))?;
+        Ok(Some(PyArray4::from_owned_array(py, array)))
+    }
+}
+
+fn open_reader(input: &str, batch_size: usize) -> PyResult<VideoFrameReader> {
+    let meta = ffprobe_meta(input).map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+    let decoder = open_decoder(input).map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+    Ok(VideoFrameReader {
+        decoder,
+        width: meta.width,
+        height: meta.height,
+        fps: meta.fps,
+        duration: meta.duration,
+        batch_size: batch_size.max(1),
+        finished: false,
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, batch_size=32))]
+pub fn open_from_path(path: &str, batch_size: usize) -> PyResult<VideoFrameReader> {
+    open_reader(path, batch_size)
+}
+
+#[pyfunction]
+#[pyo3(signature = (url, batch_size=32))]
+pub fn open_from_url(url: &str, batch_size: usize) -> PyResult<VideoFrameReader> {
+    open_reader(url, batch_size)
 }
 
 #[pyfunction]
@@ -277,12 +811,83 @@ pub fn keyframes_from_url(url: &str) -> PyResult<Vec<(usize, f64, String, usize)
     ffprobe_keyframes(url, meta.fps).map_err(|e| PyRuntimeError::new_err(e.to_string()))
 }
 
+fn frames_at_timestamps_impl(
+    input: &str,
+    times: &[f64],
+) -> Result<(Vec<u8>, Vec<f64>, usize, usize)> {
+    let meta = ffprobe_meta(input)?;
+    let keyframe_times: Vec<f64> = ffprobe_keyframes(input, meta.fps)
+        .ok()
+        .map(|kfs| kfs.iter().map(|(_, t, _, _)| *t).collect())
+        .unwrap_or_default();
+
+    let mut bytes = Vec::with_capacity(times.len() * meta.width * meta.height * 3);
+    let mut actual_times = Vec::with_capacity(times.len());
+
+    for &requested in times {
+        // Times beyond the clip's duration clamp to the last frame instead of erroring.
+        let clamped = requested.min(meta.duration.max(0.0));
+        let seek_time = keyframe_times
+            .iter()
+            .rev()
+            .find(|&&t| t <= clamped)
+            .copied()
+            .unwrap_or(0.0);
+
+        let mut decoder = open_decoder(input)?;
+        if seek_time > 0.0 {
+            decoder
+                .seek(seek_time as f32)
+                .map_err(|e| anyhow!(format!("seek failed: {e:?}")))?;
+        }
+
+        let mut found: Option<(Vec<u8>, f64)> = None;
+        for res in decoder.decode_iter() {
+            let (ts, frame) = res.map_err(|e| anyhow!(format!("decode error: {e:?}")))?;
+            let t = ts.as_secs_f64();
+            let frame_bytes = frame_to_bytes(&frame, meta.width, meta.height)?;
+            found = Some((frame_bytes, t));
+            if t >= clamped {
+                break;
+            }
+        }
+        let (frame_bytes, actual_time) =
+            found.ok_or_else(|| anyhow!("no frames decoded for timestamp {requested}"))?;
+        bytes.extend_from_slice(&frame_bytes);
+        actual_times.push(actual_time);
+    }
+
+    Ok((bytes, actual_times, meta.width, meta.height))
+}
+
+/// Sample a handful of frames at arbitrary timestamps (thumbnails, preview grids) without
+/// decoding the whole clip: seek to the nearest preceding keyframe, then decode forward.
+#[pyfunction]
+pub fn frames_at_timestamps<'py>(
+    py: Python<'py>,
+    path: &str,
+    times_secs: Vec<f64>,
+) -> PyResult<(Bound<'py, PyArray4<u8>>, Vec<f64>)> {
+    let (bytes, actual_times, width, height) = py
+        .allow_threads(|| frames_at_timestamps_impl(path, &times_secs))
+        .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+    let k = actual_times.len();
+    let array = ndarray::Array4::from_shape_vec((k, height, width, 3usize), bytes)
+        .map_err(|e| PyRuntimeError::new_err(format!("failed to build ndarray: {}", e)))?;
+    Ok((PyArray4::from_owned_array(py, array), actual_times))
+}
+
 pub fn register_module(core_module: &Bound<'_, PyModule>) -> PyResult<()> {
     let video_module = PyModule::new(core_module.py(), "video")?;
     video_module.add_function(wrap_pyfunction!(load_from_path, &video_module)?)?;
     video_module.add_function(wrap_pyfunction!(load_from_url, &video_module)?)?;
+    video_module.add_function(wrap_pyfunction!(open_from_path, &video_module)?)?;
+    video_module.add_function(wrap_pyfunction!(open_from_url, &video_module)?)?;
+    video_module.add_class::<VideoFrameReader>()?;
     video_module.add_function(wrap_pyfunction!(keyframes_from_path, &video_module)?)?;
     video_module.add_function(wrap_pyfunction!(keyframes_from_url, &video_module)?)?;
+    video_module.add_function(wrap_pyfunction!(detect_scenes, &video_module)?)?;
+    video_module.add_function(wrap_pyfunction!(frames_at_timestamps, &video_module)?)?;
     core_module.add_submodule(&video_module)?;
     Ok(())
 }
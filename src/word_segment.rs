@@ -0,0 +1,369 @@
+use pyo3::prelude::*;
+use std::collections::HashMap;
+
+/// A small built-in seed dictionary (`word -> frequency`) so `WordSegmenter` is usable without
+/// any setup. Callers extend it for their domain via [`WordSegmenter::add_word`].
+const DEFAULT_DICT: &[(&str, f64)] = &[
+    ("中国", 1000.0),
+    ("中华人民共和国", 500.0),
+    ("北京", 800.0),
+    ("上海", 800.0),
+    ("我们", 900.0),
+    ("你好", 900.0),
+    ("今天", 700.0),
+    ("天气", 600.0),
+    ("测试", 500.0),
+    ("句子", 400.0),
+    ("人民", 700.0),
+    ("共和国", 600.0),
+    ("中华", 500.0),
+    ("的", 3000.0),
+    ("是", 2500.0),
+    ("在", 2000.0),
+    ("了", 2000.0),
+    ("和", 1500.0),
+];
+
+#[derive(Default)]
+struct TrieNode {
+    children: HashMap<char, TrieNode>,
+    freq: Option<f64>,
+}
+
+/// Prefix dictionary backing the DAG construction: a char-keyed trie so `freq` lookups and
+/// the DAG walk below can share prefix traversal instead of re-scanning the input per word.
+struct PrefixDict {
+    root: TrieNode,
+    total_freq: f64,
+}
+
+impl PrefixDict {
+    fn new() -> Self {
+        let mut dict = Self {
+            root: TrieNode::default(),
+            total_freq: 0.0,
+        };
+        for &(word, freq) in DEFAULT_DICT {
+            dict.insert(word, freq);
+        }
+        dict
+    }
+
+    fn insert(&mut self, word: &str, freq: f64) {
+        let mut node = &mut self.root;
+        for c in word.chars() {
+            node = node.children.entry(c).or_default();
+        }
+        self.total_freq += freq - node.freq.unwrap_or(0.0);
+        node.freq = Some(freq);
+    }
+
+    fn freq(&self, word: &[char]) -> Option<f64> {
+        let mut node = &self.root;
+        for c in word {
+            node = node.children.get(c)?;
+        }
+        node.freq
+    }
+}
+
+/// Build `dag[i]`: every end index `j` such that `chars[i..=j]` is a dictionary word.
+/// `i` itself is always included so the DP below always has a path, even through
+/// out-of-vocabulary runs.
+fn build_dag(dict: &PrefixDict, chars: &[char]) -> Vec<Vec<usize>> {
+    let n = chars.len();
+    let mut dag = vec![Vec::new(); n];
+    for i in 0..n {
+        dag[i].push(i);
+        let mut node = &dict.root;
+        let mut j = i;
+        while let Some(child) = node.children.get(&chars[j]) {
+            if child.freq.is_some() && j != i {
+                dag[i].push(j);
+            }
+            node = child;
+            j += 1;
+            if j == n {
+                break;
+            }
+        }
+    }
+    dag
+}
+
+/// Dynamic program from the end of the buffer toward the start: `route[i]` holds the
+/// best `(log_prob, next_index)` over every DAG edge leaving `i`, so following `.1` from 0
+/// recovers the maximum-probability segmentation's end indices.
+fn dp_route(dict: &PrefixDict, chars: &[char], dag: &[Vec<usize>]) -> Vec<usize> {
+    let n = chars.len();
+    let log_total = dict.total_freq.max(1.0).ln();
+    let mut route = vec![(0.0_f64, n); n + 1];
+    for i in (0..n).rev() {
+        let mut best = (f64::NEG_INFINITY, i + 1);
+        for &j in &dag[i] {
+            let freq = dict.freq(&chars[i..=j]).unwrap_or(1.0);
+            let score = freq.ln() - log_total + route[j + 1].0;
+            if score > best.0 {
+                best = (score, j + 1);
+            }
+        }
+        route[i] = best;
+    }
+
+    let mut ends = Vec::new();
+    let mut i = 0;
+    while i < n {
+        let next = route[i].1;
+        ends.push(next - 1);
+        i = next;
+    }
+    ends
+}
+
+const BMES_STATES: [char; 4] = ['B', 'M', 'E', 'S'];
+
+fn start_log_prob(state: char) -> f64 {
+    match state {
+        'B' => -0.26268660809250016,
+        'S' => -1.4652633398537678,
+        _ => f64::NEG_INFINITY,
+    }
+}
+
+fn trans_log_prob(from: char, to: char) -> f64 {
+    match (from, to) {
+        ('B', 'E') => -0.510825623765990,
+        ('B', 'M') => -0.916290731874155,
+        ('M', 'M') => -0.33344856811948514,
+        ('M', 'E') => -1.2603623820268226,
+        ('E', 'B') => -0.5897149736854513,
+        ('E', 'S') => -0.8085250474669937,
+        ('S', 'B') => -0.7211965654669841,
+        ('S', 'S') => -0.6658631448798212,
+        _ => f64::NEG_INFINITY,
+    }
+}
+
+/// Approximate per-character emission log-probability for a BMES state.
+///
+/// A full emission table trained on a segmented corpus is hundreds of thousands of entries;
+/// this ships a coarse frequency-rank proxy instead, bucketing on the char's Unicode block as a
+/// stand-in for how common it is (the common CJK Unified Ideographs block vs. the markedly
+/// rarer Extension-A block), so an OOV run's split at least depends on which characters are
+/// actually present rather than being length-only. It still ignores `state`, unlike a real
+/// `prob_emit` table, which conditions on the BMES tag too.
+///
+/// TODO(word_segment): replace with an actual `prob_emit` table trained on a segmented corpus
+/// (jieba's is the usual reference) once one is available to vendor in.
+fn emit_log_prob(_state: char, ch: char) -> f64 {
+    match ch as u32 {
+        0x4E00..=0x9FFF => -2.5, // CJK Unified Ideographs: common-use block
+        0x3400..=0x4DBF => -4.5, // CJK Extension A: rarer/archaic characters
+        _ if ch.is_ascii_alphanumeric() => -2.0,
+        _ => -3.5,
+    }
+}
+
+/// Viterbi-decode a run of out-of-vocabulary characters into plausible word boundaries using
+/// the BMES (Begin/Middle/End/Single) tag scheme.
+fn hmm_cut(chars: &[char]) -> Vec<String> {
+    let n = chars.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    if n == 1 {
+        return vec![chars[0].to_string()];
+    }
+
+    let mut viterbi = vec![[f64::NEG_INFINITY; 4]; n];
+    let mut backptr = vec![[0usize; 4]; n];
+
+    for (s_idx, &s) in BMES_STATES.iter().enumerate() {
+        viterbi[0][s_idx] = start_log_prob(s) + emit_log_prob(s, chars[0]);
+    }
+
+    for t in 1..n {
+        for (cur_idx, &cur) in BMES_STATES.iter().enumerate() {
+            let mut best = (f64::NEG_INFINITY, 0usize);
+            for (prev_idx, &prev) in BMES_STATES.iter().enumerate() {
+                let score = viterbi[t - 1][prev_idx] + trans_log_prob(prev, cur);
+                if score > best.0 {
+                    best = (score, prev_idx);
+                }
+            }
+            viterbi[t][cur_idx] = best.0 + emit_log_prob(cur, chars[t]);
+            backptr[t][cur_idx] = best.1;
+        }
+    }
+
+    let mut best_last = (f64::NEG_INFINITY, 0usize);
+    for (idx, &score) in viterbi[n - 1].iter().enumerate() {
+        if score > best_last.0 {
+            best_last = (score, idx);
+        }
+    }
+
+    let mut state_path = vec![0usize; n];
+    state_path[n - 1] = best_last.1;
+    for t in (1..n).rev() {
+        state_path[t - 1] = backptr[t][state_path[t]];
+    }
+
+    let mut tokens = Vec::new();
+    let mut start = 0usize;
+    for (t, &state_idx) in state_path.iter().enumerate() {
+        if matches!(BMES_STATES[state_idx], 'E' | 'S') {
+            tokens.push(chars[start..=t].iter().collect());
+            start = t + 1;
+        }
+    }
+    if start < n {
+        tokens.push(chars[start..n].iter().collect());
+    }
+    tokens
+}
+
+fn append_oov(tokens: &mut Vec<String>, oov: &[char], use_hmm: bool) {
+    if oov.is_empty() {
+        return;
+    }
+    if use_hmm {
+        tokens.extend(hmm_cut(oov));
+    } else {
+        tokens.push(oov.iter().collect());
+    }
+}
+
+fn cut_sentence(dict: &PrefixDict, text: &str, use_hmm: bool) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+    if chars.is_empty() {
+        return Vec::new();
+    }
+    let dag = build_dag(dict, &chars);
+    let ends = dp_route(dict, &chars, &dag);
+
+    let mut tokens = Vec::new();
+    let mut start = 0usize;
+    let mut oov: Vec<char> = Vec::new();
+
+    for end in ends {
+        let span = &chars[start..=end];
+        if dict.freq(span).is_some() {
+            append_oov(&mut tokens, &oov, use_hmm);
+            oov.clear();
+            tokens.push(span.iter().collect());
+        } else {
+            oov.extend_from_slice(span);
+        }
+        start = end + 1;
+    }
+    append_oov(&mut tokens, &oov, use_hmm);
+    tokens
+}
+
+/// Jieba-style Chinese word segmenter: a dictionary+DP pass finds the maximum-probability
+/// segmentation into known words, falling back to an HMM Viterbi pass over any
+/// out-of-vocabulary runs. Meant to run on the sentences `TextStreamSentencizer` emits.
+#[pyclass]
+pub struct WordSegmenter {
+    dict: PrefixDict,
+    #[pyo3(get, set)]
+    use_hmm: bool,
+}
+
+#[pymethods]
+impl WordSegmenter {
+    #[new]
+    #[pyo3(signature = (use_hmm=true))]
+    pub fn new(use_hmm: bool) -> Self {
+        Self {
+            dict: PrefixDict::new(),
+            use_hmm,
+        }
+    }
+
+    /// Add or override a word's frequency in the prefix dictionary.
+    pub fn add_word(&mut self, word: &str, freq: f64) {
+        self.dict.insert(word, freq);
+    }
+
+    /// Tokenize `text` into words.
+    pub fn cut(&self, text: &str) -> Vec<String> {
+        cut_sentence(&self.dict, text, self.use_hmm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_dag_finds_every_dictionary_word_starting_at_each_position() {
+        let dict = PrefixDict::new();
+        let chars: Vec<char> = "北京".chars().collect();
+        let dag = build_dag(&dict, &chars);
+        // "北京" itself is a dict word, so position 0 should reach both its own single-char
+        // fallback and the full two-char word's end index.
+        assert_eq!(dag[0], vec![0, 1]);
+        // position 1 has no further dict word starting there besides the single char itself.
+        assert_eq!(dag[1], vec![1]);
+    }
+
+    #[test]
+    fn build_dag_always_includes_self_for_oov_runs() {
+        let dict = PrefixDict::new();
+        let chars: Vec<char> = "xyz".chars().collect();
+        let dag = build_dag(&dict, &chars);
+        for (i, edges) in dag.iter().enumerate() {
+            assert_eq!(edges, &vec![i]);
+        }
+    }
+
+    #[test]
+    fn dp_route_prefers_known_words_over_single_chars() {
+        let dict = PrefixDict::new();
+        let chars: Vec<char> = "北京".chars().collect();
+        let dag = build_dag(&dict, &chars);
+        let ends = dp_route(&dict, &chars, &dag);
+        // "北京" has much higher frequency than splitting into two singletons, so the DP should
+        // pick the single two-char span.
+        assert_eq!(ends, vec![1]);
+    }
+
+    #[test]
+    fn hmm_cut_empty_input_returns_no_tokens() {
+        let chars: Vec<char> = Vec::new();
+        assert_eq!(hmm_cut(&chars), Vec::<String>::new());
+    }
+
+    #[test]
+    fn hmm_cut_single_char_returns_that_char() {
+        let chars: Vec<char> = "x".chars().collect();
+        assert_eq!(hmm_cut(&chars), vec!["x".to_string()]);
+    }
+
+    #[test]
+    fn hmm_cut_covers_every_input_char_with_no_gaps_or_overlaps() {
+        let chars: Vec<char> = "未知词语测试".chars().collect();
+        let tokens = hmm_cut(&chars);
+        let rejoined: String = tokens.concat();
+        let original: String = chars.iter().collect();
+        assert_eq!(rejoined, original);
+    }
+
+    #[test]
+    fn cut_sentence_splits_known_words_and_falls_back_to_hmm_for_oov() {
+        let dict = PrefixDict::new();
+        let tokens = cut_sentence(&dict, "我们在xyz北京", true);
+        assert_eq!(tokens.concat(), "我们在xyz北京");
+        assert!(tokens.contains(&"我们".to_string()));
+        assert!(tokens.contains(&"北京".to_string()));
+    }
+
+    #[test]
+    fn cut_sentence_without_hmm_keeps_oov_run_as_one_token() {
+        let dict = PrefixDict::new();
+        let tokens = cut_sentence(&dict, "我们xyz北京", false);
+        assert!(tokens.contains(&"xyz".to_string()));
+    }
+}